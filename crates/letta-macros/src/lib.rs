@@ -0,0 +1,264 @@
+//! Procedural macros for the `letta` crate.
+
+use std::collections::HashMap;
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{FnArg, GenericArgument, ItemFn, Pat, PathArguments, Type};
+
+/// Turn an ordinary Rust function into a Letta tool definition.
+///
+/// The function's name becomes the tool name, its doc comment becomes the
+/// tool description, and its parameters are mapped to a JSON schema. Doc
+/// comment lines of the form `` * `param` - description `` (the usual
+/// rustdoc argument-list style) are mapped onto that parameter's schema
+/// description.
+///
+/// The original function is left untouched. A sibling `<name>_tool()`
+/// function is generated next to it, returning a
+/// [`letta::types::ToolCreate`](https://docs.rs/letta) ready to hand to
+/// the tools API for upload.
+///
+/// ```
+/// use letta_macros::letta_tool;
+///
+/// /// Get the current weather for a city.
+/// ///
+/// /// * `city` - Name of the city to look up.
+/// #[letta_tool]
+/// fn get_weather(city: String) -> String {
+///     format!("sunny in {city}")
+/// }
+///
+/// let tool = get_weather_tool();
+/// assert_eq!(tool.name, "get_weather");
+/// ```
+#[proc_macro_attribute]
+pub fn letta_tool(_attr: TokenStream, item: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(item as ItemFn);
+    expand(input).into()
+}
+
+fn expand(input: ItemFn) -> TokenStream2 {
+    let tool_name = input.sig.ident.to_string();
+    let tool_ctor = format_ident!("{}_tool", input.sig.ident);
+    let source_code = quote!(#input).to_string();
+
+    let doc = parse_doc(&input.attrs);
+    let description = doc.summary;
+
+    let mut property_entries = Vec::new();
+    let mut required_names = Vec::new();
+
+    for arg in &input.sig.inputs {
+        let FnArg::Typed(pat_type) = arg else {
+            continue;
+        };
+        let Some(name) = pat_ident(&pat_type.pat) else {
+            continue;
+        };
+
+        let (inner_ty, optional) = unwrap_option(&pat_type.ty);
+        let schema_type = json_type_for(inner_ty);
+        let description = doc.params.get(&name).cloned().unwrap_or_default();
+
+        property_entries.push(quote! {
+            properties.insert(
+                #name.to_string(),
+                ::letta::__private::serde_json::json!({
+                    "type": #schema_type,
+                    "description": #description,
+                }),
+            );
+        });
+
+        if !optional {
+            required_names.push(name);
+        }
+    }
+
+    quote! {
+        #input
+
+        #[doc = "Builds the `ToolCreate` payload for uploading this function as a Letta tool."]
+        pub fn #tool_ctor() -> ::letta::types::ToolCreate {
+            let mut properties = ::letta::__private::serde_json::Map::new();
+            #(#property_entries)*
+
+            let json_schema = ::letta::__private::serde_json::json!({
+                "name": #tool_name,
+                "description": #description,
+                "parameters": {
+                    "type": "object",
+                    "properties": properties,
+                    "required": [#(#required_names),*],
+                },
+            });
+
+            ::letta::types::ToolCreate {
+                name: #tool_name.to_string(),
+                description: Some(#description.to_string()),
+                source_code: #source_code.to_string(),
+                json_schema,
+            }
+        }
+    }
+}
+
+/// A function's doc comment, split into a summary and per-parameter notes.
+struct Doc {
+    summary: String,
+    params: HashMap<String, String>,
+}
+
+fn parse_doc(attrs: &[syn::Attribute]) -> Doc {
+    let mut lines = Vec::new();
+    for attr in attrs {
+        if !attr.path().is_ident("doc") {
+            continue;
+        }
+        if let syn::Meta::NameValue(nv) = &attr.meta {
+            if let syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Str(s),
+                ..
+            }) = &nv.value
+            {
+                lines.push(s.value().trim().to_string());
+            }
+        }
+    }
+
+    let mut summary_lines = Vec::new();
+    let mut params = HashMap::new();
+    for line in &lines {
+        match parse_param_line(line) {
+            Some((name, description)) => {
+                params.insert(name, description);
+            }
+            None if params.is_empty() && !line.is_empty() => summary_lines.push(line.clone()),
+            None => {}
+        }
+    }
+
+    Doc {
+        summary: summary_lines.join(" "),
+        params,
+    }
+}
+
+/// Parse a rustdoc argument-list line like `` * `city` - Name of the city. ``
+fn parse_param_line(line: &str) -> Option<(String, String)> {
+    let line = line.trim().trim_start_matches(['-', '*']).trim();
+    let rest = line.strip_prefix('`')?;
+    let (name, rest) = rest.split_once('`')?;
+    let description = rest.trim().trim_start_matches([':', '-']).trim();
+    Some((name.to_string(), description.to_string()))
+}
+
+fn pat_ident(pat: &Pat) -> Option<String> {
+    match pat {
+        Pat::Ident(pat_ident) => Some(pat_ident.ident.to_string()),
+        _ => None,
+    }
+}
+
+/// If `ty` is `Option<T>`, returns `(T, true)`; otherwise `(ty, false)`.
+fn unwrap_option(ty: &Type) -> (&Type, bool) {
+    if let Type::Path(type_path) = ty {
+        if let Some(segment) = type_path.path.segments.last() {
+            if segment.ident == "Option" {
+                if let PathArguments::AngleBracketed(args) = &segment.arguments {
+                    if let Some(GenericArgument::Type(inner)) = args.args.first() {
+                        return (inner, true);
+                    }
+                }
+            }
+        }
+    }
+    (ty, false)
+}
+
+/// Map a Rust type to its JSON schema `type` keyword, defaulting to
+/// `"string"` for anything we don't recognize (structs, enums, ...).
+fn json_type_for(ty: &Type) -> &'static str {
+    let Type::Path(type_path) = ty else {
+        return "string";
+    };
+    let Some(segment) = type_path.path.segments.last() else {
+        return "string";
+    };
+
+    match segment.ident.to_string().as_str() {
+        "bool" => "boolean",
+        "i8" | "i16" | "i32" | "i64" | "i128" | "isize" | "u8" | "u16" | "u32" | "u64" | "u128"
+        | "usize" => "integer",
+        "f32" | "f64" => "number",
+        _ => "string",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_param_line_reads_name_and_description() {
+        assert_eq!(
+            parse_param_line("* `city` - Name of the city."),
+            Some(("city".to_string(), "Name of the city.".to_string())),
+        );
+    }
+
+    #[test]
+    fn parse_param_line_accepts_colon_separator() {
+        assert_eq!(
+            parse_param_line("* `city`: Name of the city."),
+            Some(("city".to_string(), "Name of the city.".to_string())),
+        );
+    }
+
+    #[test]
+    fn parse_param_line_rejects_non_param_lines() {
+        assert_eq!(parse_param_line("Get the current weather for a city."), None);
+    }
+
+    #[test]
+    fn parse_doc_splits_summary_from_params() {
+        let attrs: Vec<syn::Attribute> = vec![
+            syn::parse_quote!(#[doc = " Get the current weather for a city."]),
+            syn::parse_quote!(#[doc = ""]),
+            syn::parse_quote!(#[doc = " * `city` - Name of the city to look up."]),
+        ];
+        let doc = parse_doc(&attrs);
+        assert_eq!(doc.summary, "Get the current weather for a city.");
+        assert_eq!(
+            doc.params.get("city").map(String::as_str),
+            Some("Name of the city to look up.")
+        );
+    }
+
+    #[test]
+    fn unwrap_option_strips_option() {
+        let ty: Type = syn::parse_quote!(Option<String>);
+        let (inner, optional) = unwrap_option(&ty);
+        assert!(optional);
+        assert_eq!(json_type_for(inner), "string");
+    }
+
+    #[test]
+    fn unwrap_option_passes_through_non_option() {
+        let ty: Type = syn::parse_quote!(u32);
+        let (inner, optional) = unwrap_option(&ty);
+        assert!(!optional);
+        assert_eq!(json_type_for(inner), "integer");
+    }
+
+    #[test]
+    fn json_type_for_maps_primitives() {
+        assert_eq!(json_type_for(&syn::parse_quote!(bool)), "boolean");
+        assert_eq!(json_type_for(&syn::parse_quote!(f64)), "number");
+        assert_eq!(json_type_for(&syn::parse_quote!(String)), "string");
+        assert_eq!(json_type_for(&syn::parse_quote!(Vec<String>)), "string");
+    }
+}
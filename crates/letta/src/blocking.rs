@@ -0,0 +1,135 @@
+//! A synchronous wrapper over the async client, for callers that don't want
+//! to pull in a tokio runtime of their own.
+//!
+//! This mirrors [`crate::ClientBuilder`]/[`crate::Client`] one-for-one and
+//! shares the same wire types (`CreateMessagesRequest`, `LettaMessageUnion`,
+//! `LettaId`, ...) — only the request methods lose their `.await`, by
+//! blocking on a runtime owned by the client.
+//!
+//! ```no_run
+//! use letta::blocking::ClientBuilder;
+//! use letta::types::{CreateMessagesRequest, MessageCreate, LettaId};
+//! use std::str::FromStr;
+//!
+//! # fn run() -> Result<(), letta::Error> {
+//! let client = ClientBuilder::new().base_url("http://localhost:8283").build()?;
+//! let agent_id = LettaId::from_str("agent-d93e0978-c442-4425-ba5d-a4bf3c4096e5")?;
+//! let request = CreateMessagesRequest {
+//!     messages: vec![MessageCreate::user("hello")],
+//!     ..Default::default()
+//! };
+//! let response = client.messages().create(&agent_id, request)?;
+//! # let _ = response;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::runtime::Runtime;
+
+use crate::client::{Client as AsyncClient, ClientBuilder as AsyncClientBuilder};
+use crate::messages::MessagesClient as AsyncMessagesClient;
+use crate::types::{CreateMessagesRequest, CreateMessagesResponse, LettaId};
+use crate::Result;
+
+/// Builds a [`Client`].
+#[derive(Debug, Clone)]
+pub struct ClientBuilder {
+    inner: AsyncClientBuilder,
+}
+
+impl Default for ClientBuilder {
+    fn default() -> Self {
+        Self {
+            inner: AsyncClientBuilder::new(),
+        }
+    }
+}
+
+impl ClientBuilder {
+    /// Create a new builder with the default local server address and a 30s timeout.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the base URL of the Letta server, e.g. `http://localhost:8283`.
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.inner = self.inner.base_url(base_url);
+        self
+    }
+
+    /// Set the per-request timeout.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.inner = self.inner.timeout(timeout);
+        self
+    }
+
+    /// Set how many times to retry a request that fails with a connection
+    /// reset or a 5xx response. Defaults to `0` (no retries).
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.inner = self.inner.max_retries(max_retries);
+        self
+    }
+
+    /// Set the backoff before the first retry; doubled after each subsequent one.
+    pub fn retry_backoff(mut self, retry_backoff: Duration) -> Self {
+        self.inner = self.inner.retry_backoff(retry_backoff);
+        self
+    }
+
+    /// Set how long to go without receiving any bytes before giving up with
+    /// [`crate::Error::Timeout`]. Defaults to the overall [`Self::timeout`].
+    pub fn low_speed_timeout(mut self, low_speed_timeout: Duration) -> Self {
+        self.inner = self.inner.low_speed_timeout(low_speed_timeout);
+        self
+    }
+
+    /// Build the client, starting the internal runtime it blocks requests on.
+    pub fn build(self) -> Result<Client> {
+        let runtime = Runtime::new()?;
+        let client = self.inner.build()?;
+        Ok(Client {
+            runtime: Arc::new(runtime),
+            client,
+        })
+    }
+}
+
+/// Synchronous entry point for talking to a Letta server.
+///
+/// Construct one with [`ClientBuilder`], then reach for [`Client::messages`].
+#[derive(Debug, Clone)]
+pub struct Client {
+    runtime: Arc<Runtime>,
+    client: AsyncClient,
+}
+
+impl Client {
+    /// Access the messages API.
+    pub fn messages(&self) -> MessagesClient {
+        MessagesClient {
+            runtime: self.runtime.clone(),
+            inner: self.client.messages(),
+        }
+    }
+}
+
+/// Client for `/v1/agents/{agent_id}/messages`.
+#[derive(Debug, Clone)]
+pub struct MessagesClient {
+    runtime: Arc<Runtime>,
+    inner: AsyncMessagesClient,
+}
+
+impl MessagesClient {
+    /// Send a turn to `agent_id` and wait for the full response.
+    pub fn create(
+        &self,
+        agent_id: &LettaId,
+        request: CreateMessagesRequest,
+    ) -> Result<CreateMessagesResponse> {
+        self.runtime.block_on(self.inner.create(agent_id, request))
+    }
+}
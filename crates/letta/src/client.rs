@@ -0,0 +1,200 @@
+//! HTTP client and configuration for the Letta API.
+
+use std::time::Duration;
+
+use url::Url;
+
+use crate::error::{Error, Result};
+use crate::retry::{is_retryable, RetryConfig};
+
+/// Builds a [`Client`].
+///
+/// # Examples
+///
+/// ```no_run
+/// use letta::ClientBuilder;
+/// use std::time::Duration;
+///
+/// let client = ClientBuilder::new()
+///     .base_url("http://localhost:8283")
+///     .timeout(Duration::from_secs(600))
+///     .max_retries(3)
+///     .build()?;
+/// # Ok::<(), letta::Error>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct ClientBuilder {
+    base_url: String,
+    timeout: Duration,
+    retry: RetryConfig,
+}
+
+impl Default for ClientBuilder {
+    fn default() -> Self {
+        Self {
+            base_url: "http://localhost:8283".to_string(),
+            timeout: Duration::from_secs(30),
+            retry: RetryConfig::default(),
+        }
+    }
+}
+
+impl ClientBuilder {
+    /// Create a new builder with the default local server address and a 30s timeout.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the base URL of the Letta server, e.g. `http://localhost:8283`.
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    /// Set the per-request timeout.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Set how many times to retry a request that fails with a connection
+    /// reset or a 5xx response. Defaults to `0` (no retries).
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.retry.max_retries = max_retries;
+        self
+    }
+
+    /// Set the backoff before the first retry; doubled after each subsequent one.
+    pub fn retry_backoff(mut self, retry_backoff: Duration) -> Self {
+        self.retry.retry_backoff = retry_backoff;
+        self
+    }
+
+    /// Set how long to go without receiving any bytes before giving up with
+    /// [`Error::Timeout`], reset after every byte read. Unlike [`Self::timeout`]
+    /// (a hard deadline for the whole request), this only fires on an actual
+    /// stall — waiting for the server to send the first byte of a response,
+    /// or a pause partway through one — so a slow-but-steady response under a
+    /// generous overall `timeout` won't trip it. Defaults to the overall
+    /// [`Self::timeout`].
+    pub fn low_speed_timeout(mut self, low_speed_timeout: Duration) -> Self {
+        self.retry.low_speed_timeout = Some(low_speed_timeout);
+        self
+    }
+
+    /// Build the client, parsing the base URL and constructing the underlying
+    /// HTTP client.
+    pub fn build(self) -> Result<Client> {
+        let base_url = Url::parse(&self.base_url)?;
+        let read_timeout = self.retry.low_speed_timeout.unwrap_or(self.timeout);
+        let http = reqwest::Client::builder()
+            .timeout(self.timeout)
+            .read_timeout(read_timeout)
+            .build()?;
+        Ok(Client {
+            http,
+            base_url,
+            retry: self.retry,
+            timeout: self.timeout,
+        })
+    }
+}
+
+/// Main entry point for talking to a Letta server.
+///
+/// Construct one with [`ClientBuilder`], then reach for a resource-specific
+/// client like [`crate::messages::MessagesClient`] via [`Client::messages`].
+#[derive(Debug, Clone)]
+pub struct Client {
+    pub(crate) http: reqwest::Client,
+    pub(crate) base_url: Url,
+    pub(crate) retry: RetryConfig,
+    pub(crate) timeout: Duration,
+}
+
+impl Client {
+    /// Join a path onto this client's base URL.
+    pub(crate) fn url(&self, path: &str) -> Result<Url> {
+        self.base_url.join(path).map_err(Error::from)
+    }
+}
+
+/// Turn a transport error into [`Error::Timeout`] if it timed out, or the
+/// ordinary [`Error::Http`] otherwise. Any timeout counts: a stalled read (no
+/// bytes for [`RetryConfig::low_speed_timeout`]) and the overall request
+/// deadline are not told apart, and both carry the low-speed duration (the
+/// overall timeout when none is set). `send_with_retry` only covers the
+/// initial `send()`; reading the body afterwards (`.json()`, `.chunk()`) can
+/// stall just as easily and needs the same translation.
+pub(crate) fn timeout_or_http(client: &Client, err: reqwest::Error) -> Error {
+    if err.is_timeout() {
+        Error::Timeout(client.retry.low_speed_timeout.unwrap_or(client.timeout))
+    } else {
+        Error::from(err)
+    }
+}
+
+/// Turn a response into an `Err(Error::Api)` if the server didn't return 2xx.
+pub(crate) async fn check_status(response: reqwest::Response) -> Result<reqwest::Response> {
+    if response.status().is_success() {
+        Ok(response)
+    } else {
+        let status = response.status();
+        let body = response.text().await.unwrap_or_default();
+        Err(Error::Api { status, body })
+    }
+}
+
+/// Send a request built fresh by `build` on each attempt, retrying on
+/// connection resets and 5xx responses per `client`'s [`RetryConfig`]. If a
+/// retry was actually attempted, the final failure is surfaced as
+/// [`Error::Retryable`] rather than the raw connection error or
+/// [`Error::Api`], so callers can tell "failed after retrying" apart from
+/// "failed on the first try".
+///
+/// A timeout while waiting for the server to respond at all is reported as
+/// [`Error::Timeout`] rather than retried: a slow local model still loading
+/// won't go faster for being asked again.
+pub(crate) async fn send_with_retry(
+    client: &Client,
+    mut build: impl FnMut() -> reqwest::RequestBuilder,
+) -> Result<reqwest::Response> {
+    let mut attempt = 0;
+    loop {
+        match build().send().await {
+            Ok(response) if response.status().is_server_error() => {
+                if attempt >= client.retry.max_retries {
+                    if attempt == 0 {
+                        return check_status(response).await;
+                    }
+                    let status = response.status();
+                    let body = response.text().await.unwrap_or_default();
+                    return Err(Error::Retryable {
+                        attempts: attempt + 1,
+                        message: format!("server returned {status}: {body}"),
+                    });
+                }
+            }
+            Ok(response) => return check_status(response).await,
+            Err(err) if err.is_timeout() => {
+                return Err(Error::Timeout(
+                    client.retry.low_speed_timeout.unwrap_or(client.timeout),
+                ));
+            }
+            Err(err) if is_retryable(&err) && attempt < client.retry.max_retries => {}
+            Err(err) => {
+                return Err(if attempt == 0 {
+                    Error::from(err)
+                } else {
+                    Error::Retryable {
+                        attempts: attempt + 1,
+                        message: err.to_string(),
+                    }
+                });
+            }
+        }
+
+        attempt += 1;
+        tokio::time::sleep(client.retry.backoff_for_attempt(attempt)).await;
+    }
+}
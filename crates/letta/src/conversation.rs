@@ -0,0 +1,101 @@
+//! A stateful wrapper over [`MessagesClient`] for simple chat-style usage.
+
+use crate::client::Client;
+use crate::messages::MessagesClient;
+use crate::types::{CreateMessagesRequest, LettaId, LettaMessageUnion, MessageCreate};
+use crate::Result;
+
+/// A running conversation with a single agent.
+///
+/// `Conversation` keeps its own copy of what's been said so callers don't
+/// have to rebuild a [`CreateMessagesRequest`] by hand on every turn, and so
+/// a UI can redraw the whole transcript from [`Conversation::turns`] and
+/// [`Conversation::history`] without re-querying the server.
+///
+/// ```no_run
+/// # async fn run() -> Result<(), letta::Error> {
+/// use letta::{ClientBuilder, Conversation};
+/// use letta::types::LettaId;
+/// use std::str::FromStr;
+///
+/// let client = ClientBuilder::new().base_url("http://localhost:8283").build()?;
+/// let agent_id = LettaId::from_str("agent-d93e0978-c442-4425-ba5d-a4bf3c4096e5")?;
+/// let mut conversation = Conversation::new(client, agent_id).system("Be concise.");
+///
+/// for message in conversation.send("hello").await? {
+///     println!("{message:?}");
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Conversation {
+    messages: MessagesClient,
+    agent_id: LettaId,
+    system: Option<String>,
+    turns: Vec<MessageCreate>,
+    history: Vec<LettaMessageUnion>,
+}
+
+impl Conversation {
+    /// Start a new, empty conversation with `agent_id`.
+    pub fn new(client: Client, agent_id: LettaId) -> Self {
+        Self {
+            messages: client.messages(),
+            agent_id,
+            system: None,
+            turns: Vec::new(),
+            history: Vec::new(),
+        }
+    }
+
+    /// Set a system preamble, sent once alongside the first user turn.
+    pub fn system(mut self, preamble: impl Into<String>) -> Self {
+        self.system = Some(preamble.into());
+        self
+    }
+
+    /// Send a user turn and wait for the agent's reply.
+    ///
+    /// Returns the messages the agent produced for this turn; the same
+    /// messages are also appended to [`Conversation::history`].
+    pub async fn send(&mut self, text: impl Into<String>) -> Result<Vec<LettaMessageUnion>> {
+        let user_turn = MessageCreate::user(text);
+
+        let mut messages = Vec::with_capacity(2);
+        if self.turns.is_empty() {
+            if let Some(system) = &self.system {
+                messages.push(MessageCreate::system(system.clone()));
+            }
+        }
+        messages.push(user_turn.clone());
+
+        let request = CreateMessagesRequest {
+            messages,
+            ..Default::default()
+        };
+        let response = self.messages.create(&self.agent_id, request).await?;
+
+        self.turns.push(user_turn);
+        self.history.extend(response.messages.iter().cloned());
+
+        Ok(response.messages)
+    }
+
+    /// The user turns sent so far, oldest first.
+    pub fn turns(&self) -> &[MessageCreate] {
+        &self.turns
+    }
+
+    /// Every message the agent has sent back so far, oldest first.
+    pub fn history(&self) -> &[LettaMessageUnion] {
+        &self.history
+    }
+
+    /// Forget all local turn/history state (the agent's own server-side
+    /// memory is untouched).
+    pub fn clear(&mut self) {
+        self.turns.clear();
+        self.history.clear();
+    }
+}
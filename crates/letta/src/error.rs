@@ -0,0 +1,65 @@
+//! Error types returned by the Letta client.
+
+use std::time::Duration;
+
+/// Result alias used throughout the crate.
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Errors that can occur while talking to a Letta server.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// The base URL passed to [`crate::ClientBuilder::base_url`] could not be parsed.
+    #[error("invalid base url: {0}")]
+    InvalidUrl(#[from] url::ParseError),
+
+    /// A [`crate::types::LettaId`] could not be parsed from its string form.
+    #[error("invalid letta id: {0}")]
+    InvalidId(String),
+
+    /// The underlying HTTP request failed (connection error, TLS error, etc).
+    #[cfg(any(feature = "async", feature = "blocking"))]
+    #[error("request failed: {0}")]
+    Http(#[from] reqwest::Error),
+
+    /// The server returned a response body that didn't match the expected shape.
+    #[error("failed to decode response: {0}")]
+    Decode(#[from] serde_json::Error),
+
+    /// The server returned a non-2xx status code.
+    #[cfg(any(feature = "async", feature = "blocking"))]
+    #[error("server returned {status}: {body}")]
+    Api {
+        /// HTTP status code returned by the server.
+        status: reqwest::StatusCode,
+        /// Raw response body, for debugging.
+        body: String,
+    },
+
+    /// The internal tokio runtime backing [`crate::blocking::Client`] failed to start.
+    #[cfg(feature = "blocking")]
+    #[error("failed to start runtime: {0}")]
+    Runtime(#[from] std::io::Error),
+
+    /// No bytes arrived for the configured
+    /// [`crate::ClientBuilder::low_speed_timeout`] — either the server never
+    /// started responding, or a response stalled partway through. For a
+    /// local model this usually means it's still loading into memory rather
+    /// than that something has gone wrong. Also returned when the overall
+    /// request timeout elapses; the duration carried is still the low-speed
+    /// timeout (or the overall timeout when none is set).
+    #[error("server did not respond within {0:?} (still loading?)")]
+    Timeout(Duration),
+
+    /// A request that looked transient (connection reset, 5xx) kept failing
+    /// across every retry allowed by [`crate::ClientBuilder::max_retries`].
+    /// Only returned once at least one retry was attempted; a failure on the
+    /// first try (e.g. with retries disabled) surfaces as [`Error::Http`] or
+    /// [`Error::Api`] instead.
+    #[error("request failed after {attempts} attempt(s): {message}")]
+    Retryable {
+        /// Total number of attempts made, including the first.
+        attempts: u32,
+        /// Display of the last error encountered.
+        message: String,
+    },
+}
@@ -0,0 +1,52 @@
+//! A small async client for the [Letta](https://letta.com) agent server.
+//!
+//! ```no_run
+//! use letta::ClientBuilder;
+//! use letta::types::{CreateMessagesRequest, MessageCreate, LettaId};
+//! use std::str::FromStr;
+//!
+//! # async fn run() -> Result<(), letta::Error> {
+//! let client = ClientBuilder::new().base_url("http://localhost:8283").build()?;
+//! let agent_id = LettaId::from_str("agent-d93e0978-c442-4425-ba5d-a4bf3c4096e5")?;
+//! let request = CreateMessagesRequest {
+//!     messages: vec![MessageCreate::user("hello")],
+//!     ..Default::default()
+//! };
+//! let response = client.messages().create(&agent_id, request).await?;
+//! # let _ = response;
+//! # Ok(())
+//! # }
+//! ```
+
+#[cfg(any(feature = "async", feature = "blocking"))]
+mod client;
+#[cfg(feature = "blocking")]
+pub mod blocking;
+#[cfg(feature = "async")]
+mod conversation;
+mod error;
+#[cfg(any(feature = "async", feature = "blocking"))]
+mod messages;
+#[cfg(any(feature = "async", feature = "blocking"))]
+mod retry;
+#[cfg(any(feature = "async", feature = "blocking"))]
+pub mod types;
+
+#[cfg(feature = "async")]
+pub use client::{Client, ClientBuilder};
+#[cfg(feature = "async")]
+pub use conversation::Conversation;
+pub use error::{Error, Result};
+#[cfg(feature = "async")]
+pub use messages::MessagesClient;
+#[cfg(any(feature = "async", feature = "blocking"))]
+pub use retry::RetryConfig;
+#[cfg(feature = "macros")]
+pub use letta_macros::letta_tool;
+
+/// Re-exports used by the code `letta-macros` generates, so downstream
+/// crates using `#[letta_tool]` don't need a direct `serde_json` dependency.
+#[doc(hidden)]
+pub mod __private {
+    pub use serde_json;
+}
@@ -0,0 +1,169 @@
+//! The messages API: sending turns to an agent and reading back its replies.
+
+#[cfg(feature = "async")]
+use futures::Stream;
+
+use crate::client::{send_with_retry, timeout_or_http, Client};
+use crate::error::Result;
+#[cfg(feature = "async")]
+use crate::types::LettaMessageUnion;
+use crate::types::{CreateMessagesRequest, CreateMessagesResponse, LettaId};
+
+impl Client {
+    /// Access the messages API.
+    pub fn messages(&self) -> MessagesClient {
+        MessagesClient {
+            client: self.clone(),
+        }
+    }
+}
+
+/// Client for `/v1/agents/{agent_id}/messages`.
+#[derive(Debug, Clone)]
+pub struct MessagesClient {
+    client: Client,
+}
+
+impl MessagesClient {
+    /// Send a turn to `agent_id` and wait for the full response.
+    pub async fn create(
+        &self,
+        agent_id: &LettaId,
+        request: CreateMessagesRequest,
+    ) -> Result<CreateMessagesResponse> {
+        let url = self
+            .client
+            .url(&format!("v1/agents/{agent_id}/messages"))?;
+        let response =
+            send_with_retry(&self.client, || self.client.http.post(url.clone()).json(&request))
+                .await?;
+        response
+            .json()
+            .await
+            .map_err(|err| timeout_or_http(&self.client, err))
+    }
+
+    /// Send a turn to `agent_id`, streaming back each message as it arrives
+    /// instead of waiting for the agent to finish the whole turn.
+    ///
+    /// Letta's streaming endpoint emits a `text/event-stream` body: one
+    /// `data: <json>` frame per message, terminated by a literal `data: [DONE]`
+    /// frame. Each JSON frame decodes to the same [`LettaMessageUnion`] that
+    /// [`Self::create`] returns in bulk, so callers can render
+    /// `ReasoningMessage`/`AssistantMessage` deltas live instead of blocking
+    /// for however long a slow local model takes to finish a turn.
+    ///
+    /// Only available with the `async` feature: [`crate::blocking`] has no
+    /// streaming counterpart, since blocking on a `Stream` item-by-item
+    /// would defeat the point of a synchronous API.
+    #[cfg(feature = "async")]
+    pub fn create_stream(
+        &self,
+        agent_id: &LettaId,
+        mut request: CreateMessagesRequest,
+    ) -> impl Stream<Item = Result<LettaMessageUnion>> {
+        request.stream = true;
+        let client = self.client.clone();
+        let agent_id = agent_id.clone();
+
+        async_stream::try_stream! {
+            let url = client.url(&format!("v1/agents/{agent_id}/messages"))?;
+            let mut response =
+                send_with_retry(&client, || client.http.post(url.clone()).json(&request)).await?;
+
+            // Buffer raw bytes rather than decoding each chunk on its own: a
+            // multi-byte UTF-8 character can land across a chunk boundary,
+            // and decoding the halves independently would corrupt it.
+            let mut buf: Vec<u8> = Vec::new();
+            while let Some(chunk) = response
+                .chunk()
+                .await
+                .map_err(|err| timeout_or_http(&client, err))?
+            {
+                buf.extend_from_slice(&chunk);
+
+                let (frames, done) = drain_sse_frames(&mut buf);
+                for frame in frames {
+                    yield serde_json::from_str::<LettaMessageUnion>(&frame)?;
+                }
+                if done {
+                    return;
+                }
+            }
+        }
+    }
+}
+
+/// Pull every complete `data: <payload>` frame out of `buf`, leaving any
+/// trailing partial line (including a partial UTF-8 character) for the next
+/// call once more bytes have arrived. Returns the payloads found, and
+/// whether the `data: [DONE]` sentinel was among them.
+#[cfg(feature = "async")]
+fn drain_sse_frames(buf: &mut Vec<u8>) -> (Vec<String>, bool) {
+    let mut frames = Vec::new();
+    let mut done = false;
+    while let Some(newline) = buf.iter().position(|&b| b == b'\n') {
+        let line_bytes: Vec<u8> = buf.drain(..=newline).collect();
+        let line = String::from_utf8_lossy(&line_bytes);
+        let line = line.trim_end();
+
+        let Some(data) = line.strip_prefix("data:") else {
+            continue;
+        };
+        let data = data.trim();
+        if data.is_empty() {
+            continue;
+        }
+        if data == "[DONE]" {
+            done = true;
+            break;
+        }
+        frames.push(data.to_string());
+    }
+    (frames, done)
+}
+
+#[cfg(all(test, feature = "async"))]
+mod tests {
+    use super::drain_sse_frames;
+
+    #[test]
+    fn drains_complete_frames_and_keeps_partial_line_buffered() {
+        let mut buf = b"data: {\"a\":1}\ndata: {\"a\":2}\nda".to_vec();
+        let (frames, done) = drain_sse_frames(&mut buf);
+        assert_eq!(frames, vec!["{\"a\":1}", "{\"a\":2}"]);
+        assert!(!done);
+        assert_eq!(buf, b"da");
+    }
+
+    #[test]
+    fn reassembles_a_multibyte_character_split_across_chunks() {
+        // "caf\u{e9}" ("café") with the two UTF-8 bytes of 'é' (0xC3 0xA9)
+        // landing in separate chunks.
+        let payload = "{\"text\":\"café\"}";
+        let bytes = payload.as_bytes();
+        // The payload ends in 0xC3 0xA9 '"' '}', so this splits inside 'é'.
+        let split = bytes.len() - 3;
+
+        let mut buf = Vec::new();
+        buf.extend_from_slice(b"data: ");
+        buf.extend_from_slice(&bytes[..split]);
+        let (frames, done) = drain_sse_frames(&mut buf);
+        assert!(frames.is_empty());
+        assert!(!done);
+
+        buf.extend_from_slice(&bytes[split..]);
+        buf.push(b'\n');
+        let (frames, done) = drain_sse_frames(&mut buf);
+        assert_eq!(frames, vec![payload]);
+        assert!(!done);
+    }
+
+    #[test]
+    fn recognizes_done_sentinel() {
+        let mut buf = b"data: [DONE]\n".to_vec();
+        let (frames, done) = drain_sse_frames(&mut buf);
+        assert!(frames.is_empty());
+        assert!(done);
+    }
+}
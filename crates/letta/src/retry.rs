@@ -0,0 +1,74 @@
+//! Retry and slow-start handling for requests against a flaky or
+//! slow-to-start server.
+
+use std::time::Duration;
+
+/// Retry/backoff behavior, configured on [`crate::ClientBuilder`].
+#[derive(Debug, Clone)]
+pub struct RetryConfig {
+    /// Number of retries after the first attempt. `0` (the default) disables
+    /// retrying entirely.
+    pub max_retries: u32,
+    /// Backoff before the first retry; doubled after each subsequent one.
+    pub retry_backoff: Duration,
+    /// How long to go without receiving any bytes before giving up with
+    /// [`crate::Error::Timeout`], reset after every successful read. Distinct
+    /// from the overall per-request timeout, which is a hard deadline for
+    /// the whole request regardless of how steadily it's progressing. Local
+    /// LLM backends often spend most of this loading a model into memory
+    /// before the first byte arrives.
+    ///
+    /// Defaults to the client's overall request timeout.
+    pub low_speed_timeout: Option<Duration>,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: 0,
+            retry_backoff: Duration::from_millis(500),
+            low_speed_timeout: None,
+        }
+    }
+}
+
+impl RetryConfig {
+    /// The backoff to wait before retry attempt number `attempt` (1-indexed).
+    pub(crate) fn backoff_for_attempt(&self, attempt: u32) -> Duration {
+        self.retry_backoff * 2u32.saturating_pow(attempt.saturating_sub(1))
+    }
+}
+
+/// Whether a transport-level error looks like a transient connection issue
+/// worth retrying (resets, refused/aborted connections), as opposed to
+/// something that will fail again immediately (bad URL, TLS misconfiguration).
+pub(crate) fn is_retryable(err: &reqwest::Error) -> bool {
+    err.is_connect() || err.is_request()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_doubles_each_attempt() {
+        let retry = RetryConfig {
+            retry_backoff: Duration::from_millis(100),
+            ..RetryConfig::default()
+        };
+        assert_eq!(retry.backoff_for_attempt(1), Duration::from_millis(100));
+        assert_eq!(retry.backoff_for_attempt(2), Duration::from_millis(200));
+        assert_eq!(retry.backoff_for_attempt(3), Duration::from_millis(400));
+    }
+
+    #[test]
+    fn backoff_does_not_panic_on_a_huge_attempt_number() {
+        let retry = RetryConfig {
+            retry_backoff: Duration::from_millis(100),
+            ..RetryConfig::default()
+        };
+        // The power of two saturates at u32::MAX instead of overflowing/panicking;
+        // this just checks the call returns rather than asserting an exact value.
+        let _ = retry.backoff_for_attempt(u32::MAX);
+    }
+}
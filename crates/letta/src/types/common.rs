@@ -0,0 +1,83 @@
+//! Common types shared across the Letta API surface.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+use uuid::Uuid;
+
+use crate::error::Error;
+
+/// A timestamp as returned by the Letta server.
+pub type Timestamp = chrono::DateTime<chrono::Utc>;
+
+/// Letta resource identifier, e.g. `agent-d93e0978-c442-4425-ba5d-a4bf3c4096e5`.
+///
+/// Letta prefixes ids with the resource kind (`agent-`, `message-`, `tool-`, ...)
+/// followed by a UUID. We keep the original string around rather than splitting
+/// it apart, since the prefix isn't meaningful to the client beyond round-tripping.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct LettaId(String);
+
+impl LettaId {
+    /// Returns the id as a plain string slice.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the bare UUID, stripping off any `<prefix>-` if present.
+    ///
+    /// A UUID's own canonical form already contains dashes, so a bare id
+    /// can't be told apart from a prefixed one by splitting alone: try
+    /// parsing the whole string first, and only fall back to stripping
+    /// everything before the first dash if that fails.
+    pub fn uuid(&self) -> std::result::Result<Uuid, uuid::Error> {
+        if let Ok(uuid) = Uuid::parse_str(&self.0) {
+            return Ok(uuid);
+        }
+        match self.0.split_once('-') {
+            Some((_, uuid)) => Uuid::parse_str(uuid),
+            None => Uuid::parse_str(&self.0),
+        }
+    }
+}
+
+impl FromStr for LettaId {
+    type Err = Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Error> {
+        if s.is_empty() {
+            return Err(Error::InvalidId("id must not be empty".into()));
+        }
+        Ok(Self(s.to_string()))
+    }
+}
+
+impl fmt::Display for LettaId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn uuid_strips_prefix_from_a_multi_dash_id() {
+        let id = LettaId::from_str("agent-d93e0978-c442-4425-ba5d-a4bf3c4096e5").unwrap();
+        assert_eq!(
+            id.uuid().unwrap(),
+            Uuid::parse_str("d93e0978-c442-4425-ba5d-a4bf3c4096e5").unwrap(),
+        );
+    }
+
+    #[test]
+    fn uuid_parses_a_bare_uuid_with_no_prefix() {
+        let id = LettaId::from_str("d93e0978-c442-4425-ba5d-a4bf3c4096e5").unwrap();
+        assert_eq!(
+            id.uuid().unwrap(),
+            Uuid::parse_str("d93e0978-c442-4425-ba5d-a4bf3c4096e5").unwrap(),
+        );
+    }
+}
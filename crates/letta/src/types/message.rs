@@ -0,0 +1,162 @@
+//! Message-related types for the Letta messages API.
+
+use serde::{Deserialize, Serialize};
+
+use super::common::{LettaId, Timestamp};
+
+/// The role a message was authored under.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MessageRole {
+    /// A message from the end user.
+    User,
+    /// A system preamble/instruction.
+    System,
+}
+
+/// A message to send to an agent.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MessageCreate {
+    /// Who the message is from.
+    pub role: MessageRole,
+    /// The message text.
+    pub content: String,
+}
+
+impl MessageCreate {
+    /// Build a user message.
+    pub fn user(content: impl Into<String>) -> Self {
+        Self {
+            role: MessageRole::User,
+            content: content.into(),
+        }
+    }
+
+    /// Build a system message.
+    pub fn system(content: impl Into<String>) -> Self {
+        Self {
+            role: MessageRole::System,
+            content: content.into(),
+        }
+    }
+}
+
+/// Request body for `POST /v1/agents/{agent_id}/messages`.
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct CreateMessagesRequest {
+    /// The turn's messages, usually a single user message.
+    pub messages: Vec<MessageCreate>,
+    /// Maximum number of agent steps to run before returning.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_steps: Option<u32>,
+    /// Whether to stream the response back as server-sent events.
+    ///
+    /// Set by [`crate::messages::MessagesClient::create_stream`]; callers using
+    /// [`crate::messages::MessagesClient::create`] don't need to touch this.
+    #[serde(default)]
+    pub stream: bool,
+}
+
+/// Response body for `POST /v1/agents/{agent_id}/messages`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct CreateMessagesResponse {
+    /// Messages produced by the agent during this turn.
+    pub messages: Vec<LettaMessageUnion>,
+}
+
+/// A tool invocation requested by the agent.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ToolCall {
+    /// Name of the tool being called.
+    pub name: String,
+    /// Arguments the agent passed, as a JSON string.
+    pub arguments: String,
+    /// Id of this tool call, used to match it with its `ToolReturnMessage`.
+    pub tool_call_id: String,
+}
+
+/// Any one of the message types a Letta agent can emit for a turn.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "message_type", rename_all = "snake_case")]
+pub enum LettaMessageUnion {
+    /// Echo of the system preamble.
+    SystemMessage(SystemMessage),
+    /// Echo of the user's message.
+    UserMessage(UserMessage),
+    /// The agent's reply.
+    AssistantMessage(AssistantMessage),
+    /// The agent's internal reasoning for this step.
+    ReasoningMessage(ReasoningMessage),
+    /// A tool the agent decided to call.
+    ToolCallMessage(ToolCallMessage),
+    /// The result of a tool call.
+    ToolReturnMessage(ToolReturnMessage),
+}
+
+/// Echo of the system preamble.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SystemMessage {
+    /// Message id.
+    pub id: LettaId,
+    /// When the message was created.
+    pub date: Timestamp,
+    /// Message content.
+    pub content: String,
+}
+
+/// Echo of the user's message.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserMessage {
+    /// Message id.
+    pub id: LettaId,
+    /// When the message was created.
+    pub date: Timestamp,
+    /// Message content.
+    pub content: String,
+}
+
+/// The agent's reply.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssistantMessage {
+    /// Message id.
+    pub id: LettaId,
+    /// When the message was created.
+    pub date: Timestamp,
+    /// Message content.
+    pub content: String,
+}
+
+/// The agent's internal reasoning for this step.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReasoningMessage {
+    /// Message id.
+    pub id: LettaId,
+    /// When the message was created.
+    pub date: Timestamp,
+    /// The reasoning text itself.
+    pub reasoning: String,
+}
+
+/// A tool the agent decided to call.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ToolCallMessage {
+    /// Message id.
+    pub id: LettaId,
+    /// When the message was created.
+    pub date: Timestamp,
+    /// The tool call itself.
+    pub tool_call: ToolCall,
+}
+
+/// The result of a tool call.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ToolReturnMessage {
+    /// Message id.
+    pub id: LettaId,
+    /// When the message was created.
+    pub date: Timestamp,
+    /// Id of the tool call this is returning a value for.
+    pub tool_call_id: String,
+    /// The tool's return value, as a string.
+    pub tool_return: String,
+}
@@ -0,0 +1,13 @@
+//! Public wire types for the Letta API.
+
+mod common;
+mod message;
+mod tool;
+
+pub use common::{LettaId, Timestamp};
+pub use message::{
+    AssistantMessage, CreateMessagesRequest, CreateMessagesResponse, LettaMessageUnion,
+    MessageCreate, MessageRole, ReasoningMessage, SystemMessage, ToolCall, ToolCallMessage,
+    ToolReturnMessage, UserMessage,
+};
+pub use tool::ToolCreate;
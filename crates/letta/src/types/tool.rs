@@ -0,0 +1,20 @@
+//! Types for registering tools a Letta agent can call.
+
+use serde::Serialize;
+
+/// Payload for uploading a tool definition to the server.
+///
+/// Usually built by the `#[letta_tool]` attribute macro from `letta-macros`
+/// rather than by hand.
+#[derive(Debug, Clone, Serialize)]
+pub struct ToolCreate {
+    /// Tool name, as the agent will refer to it.
+    pub name: String,
+    /// Human-readable description shown to the model.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The tool's source code, uploaded so the server can execute it.
+    pub source_code: String,
+    /// JSON schema describing the tool's name, description and parameters.
+    pub json_schema: serde_json::Value,
+}
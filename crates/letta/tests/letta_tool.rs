@@ -0,0 +1,50 @@
+//! Exercises `#[letta_tool]` expansion end-to-end against the real
+//! `letta::types` it generates code against, checking the full schema
+//! (descriptions, `required`) rather than only the pure helper functions
+//! unit-tested in `letta-macros` itself or the tool name its doctest checks.
+#![cfg(feature = "macros")]
+
+use letta::letta_tool;
+
+/// Get the current weather for a city.
+///
+/// * `city` - Name of the city to look up.
+/// * `units` - Temperature units, e.g. `celsius` or `fahrenheit`.
+#[letta_tool]
+fn get_weather(city: String, units: Option<String>) -> String {
+    let _ = units;
+    format!("sunny in {city}")
+}
+
+#[test]
+fn expands_a_tool_ctor_with_name_description_and_schema() {
+    let tool = get_weather_tool();
+
+    // The macro leaves the original function callable, untouched.
+    assert_eq!(get_weather("Paris".to_string(), None), "sunny in Paris");
+
+    assert_eq!(tool.name, "get_weather");
+    assert_eq!(
+        tool.description.as_deref(),
+        Some("Get the current weather for a city.")
+    );
+    assert!(tool.source_code.contains("get_weather"));
+    assert!(tool.source_code.contains("sunny in"));
+
+    let properties = tool.json_schema["parameters"]["properties"]
+        .as_object()
+        .unwrap();
+    assert_eq!(
+        properties["city"]["description"],
+        "Name of the city to look up."
+    );
+    assert_eq!(
+        properties["units"]["description"],
+        "Temperature units, e.g. `celsius` or `fahrenheit`."
+    );
+
+    let required = tool.json_schema["parameters"]["required"]
+        .as_array()
+        .unwrap();
+    assert_eq!(required, &[serde_json::json!("city")]);
+}